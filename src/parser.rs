@@ -1,10 +1,13 @@
+use std::cmp::Ordering;
 use std::fmt;
+use std::str::FromStr;
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
 #[cfg(feature = "serde")]
 use serde::{
+    de::{Deserialize, Deserializer, Error as DeError},
     ser::{SerializeStruct, Serializer},
     Serialize,
 };
@@ -73,7 +76,7 @@ impl fmt::Display for InvalidRelease {
 }
 
 /// Represents a parsed version.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Version<'a> {
     raw: &'a str,
     major: u64,
@@ -90,13 +93,14 @@ impl<'a> Serialize for Version<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("Version", 5)?;
+        let mut state = serializer.serialize_struct("Version", 7)?;
         state.serialize_field("major", &self.major())?;
         state.serialize_field("minor", &self.minor())?;
         state.serialize_field("patch", &self.patch())?;
         state.serialize_field("pre", &self.pre())?;
         state.serialize_field("build_code", &self.build_code())?;
         state.serialize_field("components", &self.components())?;
+        state.serialize_field("channel", &self.channel())?;
         state.end()
     }
 }
@@ -224,6 +228,74 @@ impl<'a> Version<'a> {
     pub fn quad(&self) -> (u64, u64, u64, Option<&str>) {
         (self.major, self.minor, self.patch, self.pre())
     }
+
+    /// Checks if this version satisfies the given requirement.
+    pub fn matches(&self, req: &VersionReq) -> bool {
+        req.matches(self)
+    }
+
+    /// Classifies the pre-release identifier into a coarse stability class.
+    ///
+    /// This looks at the first dot-separated token of the pre-release,
+    /// case-insensitively, so `1.0.0-beta.3` is `Beta` and `2.1-rc1` is
+    /// `ReleaseCandidate`.
+    pub fn channel(&self) -> ReleaseChannel<'a> {
+        let pre: &'a str = self.pre;
+        if pre.is_empty() {
+            return ReleaseChannel::Stable;
+        }
+        let token = pre.split('.').next().unwrap_or(pre);
+        let lower = token.to_ascii_lowercase();
+        if lower.starts_with("rc") {
+            ReleaseChannel::ReleaseCandidate
+        } else if lower.starts_with("beta") {
+            ReleaseChannel::Beta
+        } else if lower.starts_with("alpha") {
+            ReleaseChannel::Alpha
+        } else if lower.starts_with("dev") || lower.starts_with("nightly") {
+            ReleaseChannel::Dev
+        } else {
+            ReleaseChannel::Other(token)
+        }
+    }
+
+    /// Returns the dot-separated pre-release identifiers, each classified
+    /// as numeric or alphanumeric the way SemVer precedence does.
+    pub fn pre_identifiers(&self) -> impl Iterator<Item = Identifier<'a>> {
+        let pre = self.pre;
+        pre.split('.')
+            .filter(move |_| !pre.is_empty())
+            .map(classify_identifier)
+    }
+
+    /// Returns the dot-separated build identifiers, each classified as
+    /// numeric or alphanumeric the way SemVer precedence does.
+    pub fn build_identifiers(&self) -> impl Iterator<Item = Identifier<'a>> {
+        let build_code = self.build_code;
+        build_code
+            .split('.')
+            .filter(move |_| !build_code.is_empty())
+            .map(classify_identifier)
+    }
+}
+
+/// A coarse classification of a version's maturity, derived from its
+/// pre-release identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum ReleaseChannel<'a> {
+    /// No pre-release identifier is present.
+    Stable,
+    /// A release candidate (`rc`).
+    ReleaseCandidate,
+    /// A beta pre-release.
+    Beta,
+    /// An alpha pre-release.
+    Alpha,
+    /// A development or nightly build.
+    Dev,
+    /// An unrecognized pre-release identifier.
+    Other(&'a str),
 }
 
 impl<'a> fmt::Display for Version<'a> {
@@ -239,8 +311,319 @@ impl<'a> fmt::Display for Version<'a> {
     }
 }
 
-/// Represents a parsed release.
+/// A single dot-separated identifier from a pre-release or build string,
+/// classified the way SemVer precedence rules require.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Identifier<'a> {
+    /// A purely numeric identifier, e.g. the `7` in `1.0.0-beta.7`.
+    Numeric(u64),
+    /// Any other identifier, compared lexically.
+    AlphaNumeric(&'a str),
+}
+
+/// Classifies a single dot-separated identifier as numeric or alphanumeric.
+fn classify_identifier(s: &str) -> Identifier<'_> {
+    match s.parse::<u64>() {
+        Ok(n) => Identifier::Numeric(n),
+        Err(_) => Identifier::AlphaNumeric(s),
+    }
+}
+
+/// Compares two dot-separated pre-release identifiers for SemVer precedence.
+///
+/// A missing pre-release (empty string) has *higher* precedence than any
+/// pre-release. Shared identifiers are compared pairwise (numeric
+/// identifiers numerically, alphanumeric ones lexically, with numeric
+/// always ranking below alphanumeric); if all shared identifiers are equal
+/// the pre-release with more identifiers wins.
+fn compare_pre(a: &str, b: &str) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    let mut a_ids = a.split('.').map(classify_identifier);
+    let mut b_ids = b.split('.').map(classify_identifier);
+    loop {
+        match (a_ids.next(), b_ids.next()) {
+            (Some(a_id), Some(b_id)) => {
+                let ordering = match (a_id, b_id) {
+                    (Identifier::Numeric(a_num), Identifier::Numeric(b_num)) => a_num.cmp(&b_num),
+                    (Identifier::Numeric(_), Identifier::AlphaNumeric(_)) => Ordering::Less,
+                    (Identifier::AlphaNumeric(_), Identifier::Numeric(_)) => Ordering::Greater,
+                    (Identifier::AlphaNumeric(a_id), Identifier::AlphaNumeric(b_id)) => {
+                        a_id.cmp(b_id)
+                    }
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
+    }
+}
+
+impl<'a> PartialEq for Version<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Version<'a> {}
+
+impl<'a> PartialOrd for Version<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Version<'a> {
+    /// Orders versions by SemVer precedence.
+    ///
+    /// `major`, `minor` and `patch` are compared numerically, then the
+    /// pre-release identifiers (a version without a pre-release always
+    /// outranks one with). Build metadata is ignored entirely, and missing
+    /// components count as `0`, so e.g. `1.0` and `1.0.0` compare equal.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then_with(|| self.minor.cmp(&other.minor))
+            .then_with(|| self.patch.cmp(&other.patch))
+            .then_with(|| compare_pre(self.pre, other.pre))
+    }
+}
+
+/// An error indicating an invalid version requirement.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InvalidVersionReq;
+
+impl std::error::Error for InvalidVersionReq {}
+
+impl fmt::Display for InvalidVersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid version requirement")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComparatorOp {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Tilde,
+    Caret,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Comparator {
+    op: ComparatorOp,
+    // `None` means this comparator is a bare wildcard (`*`) and matches
+    // any version.
+    major: Option<u64>,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: String,
+}
+
+/// Computes the ordering of `version` against a synthetic `(major, minor,
+/// patch, pre)` tuple, the same way `Version::cmp` would.
+fn compare_version_tuple(
+    version: &Version<'_>,
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: &str,
+) -> Ordering {
+    version
+        .major()
+        .cmp(&major)
+        .then_with(|| version.minor().cmp(&minor))
+        .then_with(|| version.patch().cmp(&patch))
+        .then_with(|| compare_pre(version.pre().unwrap_or(""), pre))
+}
+
+impl Comparator {
+    fn matches(&self, version: &Version<'_>) -> bool {
+        let major = match self.major {
+            Some(major) => major,
+            None => return true,
+        };
+        let minor = self.minor.unwrap_or(0);
+        let patch = self.patch.unwrap_or(0);
+
+        match self.op {
+            ComparatorOp::Exact => {
+                self.major == Some(version.major())
+                    && self.minor.is_none_or(|m| m == version.minor())
+                    && self.patch.is_none_or(|p| p == version.patch())
+                    && self.pre == version.pre().unwrap_or("")
+            }
+            ComparatorOp::Greater => {
+                compare_version_tuple(version, major, minor, patch, &self.pre) == Ordering::Greater
+            }
+            ComparatorOp::GreaterEq => {
+                compare_version_tuple(version, major, minor, patch, &self.pre) != Ordering::Less
+            }
+            ComparatorOp::Less => {
+                compare_version_tuple(version, major, minor, patch, &self.pre) == Ordering::Less
+            }
+            ComparatorOp::LessEq => {
+                compare_version_tuple(version, major, minor, patch, &self.pre) != Ordering::Greater
+            }
+            ComparatorOp::Tilde => {
+                let upper = if self.minor.is_some() {
+                    (major, minor + 1, 0)
+                } else {
+                    (major + 1, 0, 0)
+                };
+                self.matches_range(version, (major, minor, patch), upper)
+            }
+            ComparatorOp::Caret => {
+                let upper = if major > 0 {
+                    (major + 1, 0, 0)
+                } else if let Some(minor) = self.minor {
+                    if minor > 0 {
+                        (0, minor + 1, 0)
+                    } else if let Some(patch) = self.patch {
+                        (0, 0, patch + 1)
+                    } else {
+                        (0, 1, 0)
+                    }
+                } else {
+                    (1, 0, 0)
+                };
+                self.matches_range(version, (major, minor, patch), upper)
+            }
+        }
+    }
+
+    fn matches_range(
+        &self,
+        version: &Version<'_>,
+        lower: (u64, u64, u64),
+        upper: (u64, u64, u64),
+    ) -> bool {
+        compare_version_tuple(version, lower.0, lower.1, lower.2, &self.pre) != Ordering::Less
+            && compare_version_tuple(version, upper.0, upper.1, upper.2, "") == Ordering::Less
+    }
+
+    /// Whether this comparator explicitly allows the pre-release carried by
+    /// `version` (same major/minor/patch, and a pre-release of its own).
+    fn allows_pre(&self, version: &Version<'_>) -> bool {
+        !self.pre.is_empty()
+            && self.major == Some(version.major())
+            && self.minor.unwrap_or(0) == version.minor()
+            && self.patch.unwrap_or(0) == version.patch()
+    }
+}
+
+lazy_static! {
+    static ref COMPARATOR_REGEX: Regex = Regex::new(
+        r#"(?x)
+        ^
+            (?P<op>>=|<=|>|<|=|~|\^)?
+            \s*
+            (?:
+                \*
+                |
+                (?P<major>0|[1-9][0-9]*)
+                (?:
+                    \.
+                    (?:\*|(?P<minor>0|[1-9][0-9]*))
+                    (?:
+                        \.
+                        (?:\*|(?P<patch>0|[1-9][0-9]*))
+                        (?:-(?P<pre>(?:0|[1-9][0-9]*|[0-9]*[a-zA-Z-][0-9a-zA-Z-]*)
+                                    (?:\.(?:0|[1-9][0-9]*|[0-9]*[a-zA-Z-][0-9a-zA-Z-]*))*))?
+                    )?
+                )?
+            )
+        $
+    "#
+    )
+    .unwrap();
+}
+
+fn parse_comparator(part: &str) -> Result<Comparator, InvalidVersionReq> {
+    let part = part.trim();
+    let caps = COMPARATOR_REGEX.captures(part).ok_or(InvalidVersionReq)?;
+
+    let op = match caps.name("op").map(|x| x.as_str()) {
+        None => ComparatorOp::Caret,
+        Some("=") => ComparatorOp::Exact,
+        Some(">") => ComparatorOp::Greater,
+        Some(">=") => ComparatorOp::GreaterEq,
+        Some("<") => ComparatorOp::Less,
+        Some("<=") => ComparatorOp::LessEq,
+        Some("~") => ComparatorOp::Tilde,
+        Some("^") => ComparatorOp::Caret,
+        Some(_) => unreachable!(),
+    };
+
+    Ok(Comparator {
+        op,
+        major: caps.name("major").map(|x| x.as_str().parse().unwrap()),
+        minor: caps.name("minor").map(|x| x.as_str().parse().unwrap()),
+        patch: caps.name("patch").map(|x| x.as_str().parse().unwrap()),
+        pre: caps
+            .name("pre")
+            .map(|x| x.as_str().to_string())
+            .unwrap_or_default(),
+    })
+}
+
+/// A parsed version requirement (e.g. `>=1.2.0 <2.0.0` or `^1.4`).
+///
+/// Requirements are built from comma- or whitespace-separated comparators which are all
+/// ANDed together; use [`Version::matches`] to test a version against one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReq {
+    comparators: Vec<Comparator>,
+}
+
+impl VersionReq {
+    /// Parses a version requirement from a string.
+    ///
+    /// Comparators can be combined into an AND range by separating them
+    /// with a comma, whitespace, or both, e.g. `>=1.2.0 <2.0.0` and
+    /// `>=1.2.0,<2.0.0` are equivalent.
+    pub fn parse(req: &str) -> Result<VersionReq, InvalidVersionReq> {
+        let mut comparators = Vec::new();
+        for part in req.split(|c: char| c == ',' || c.is_whitespace()) {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+            comparators.push(parse_comparator(part)?);
+        }
+        if comparators.is_empty() {
+            return Err(InvalidVersionReq);
+        }
+        Ok(VersionReq { comparators })
+    }
+
+    /// Checks if the given version satisfies this requirement.
+    pub fn matches(&self, version: &Version<'_>) -> bool {
+        if !self.comparators.iter().all(|c| c.matches(version)) {
+            return false;
+        }
+        if version.pre().is_some() {
+            return self.comparators.iter().any(|c| c.allows_pre(version));
+        }
+        true
+    }
+}
+
+/// Represents a parsed release.
+#[derive(Debug, Clone)]
 pub struct Release<'a> {
     raw: &'a str,
     package: &'a str,
@@ -357,6 +740,39 @@ impl<'a> Release<'a> {
     }
 }
 
+impl<'a> PartialEq for Release<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Release<'a> {}
+
+impl<'a> PartialOrd for Release<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Release<'a> {
+    /// Orders releases by `(package, version)`.
+    ///
+    /// Releases whose version part failed to parse are grouped together,
+    /// ordered by `version_raw` lexically, and always sort below releases
+    /// with a parsed version — mixing numeric precedence with a lexical
+    /// fallback in the same comparison would make the order non-transitive.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.package
+            .cmp(other.package)
+            .then_with(|| match (&self.version, &other.version) {
+                (Some(a), Some(b)) => a.cmp(b),
+                (None, None) => self.version_raw.cmp(other.version_raw),
+                (None, Some(_)) => Ordering::Less,
+                (Some(_), None) => Ordering::Greater,
+            })
+    }
+}
+
 #[derive(Debug)]
 struct VersionDescription<'a>(&'a Version<'a>);
 
@@ -436,3 +852,441 @@ impl<'a> fmt::Display for Release<'a> {
         Ok(())
     }
 }
+
+/// An owned counterpart to [`Version`].
+///
+/// `Version` borrows from the string it was parsed from, which means a
+/// parsed value cannot outlive its source buffer. `OwnedVersion` copies the
+/// relevant slices into owned `String`s instead, so it can be stored in a
+/// struct field, returned from a function, or deserialized directly from a
+/// JSON string via `serde`.
+#[derive(Debug, Clone)]
+pub struct OwnedVersion {
+    raw: String,
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: String,
+    build_code: String,
+    components: u8,
+}
+
+impl OwnedVersion {
+    /// Borrows this owned version as a regular [`Version`].
+    pub fn as_version(&self) -> Version<'_> {
+        Version {
+            raw: &self.raw,
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            pre: &self.pre,
+            build_code: &self.build_code,
+            components: self.components,
+        }
+    }
+}
+
+impl From<Version<'_>> for OwnedVersion {
+    fn from(version: Version<'_>) -> OwnedVersion {
+        OwnedVersion {
+            raw: version.raw.to_string(),
+            major: version.major,
+            minor: version.minor,
+            patch: version.patch,
+            pre: version.pre.to_string(),
+            build_code: version.build_code.to_string(),
+            components: version.components,
+        }
+    }
+}
+
+impl FromStr for OwnedVersion {
+    type Err = InvalidVersion;
+
+    fn from_str(version: &str) -> Result<OwnedVersion, InvalidVersion> {
+        Version::parse(version).map(OwnedVersion::from)
+    }
+}
+
+impl fmt::Display for OwnedVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.as_version(), f)
+    }
+}
+
+impl PartialEq for OwnedVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_version() == other.as_version()
+    }
+}
+
+impl Eq for OwnedVersion {}
+
+impl PartialOrd for OwnedVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OwnedVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_version().cmp(&other.as_version())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for OwnedVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for OwnedVersion {
+    fn deserialize<D>(deserializer: D) -> Result<OwnedVersion, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(DeError::custom)
+    }
+}
+
+/// An owned counterpart to [`Release`].
+///
+/// Like [`OwnedVersion`], this copies the parsed slices into owned
+/// `String`s so the result does not borrow from the input, making it
+/// usable in config structs and event payloads where releases arrive as
+/// JSON strings and must be round-tripped.
+#[derive(Debug, Clone)]
+pub struct OwnedRelease {
+    raw: String,
+    package: String,
+    version_raw: String,
+    version: Option<OwnedVersion>,
+}
+
+impl PartialEq for OwnedRelease {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_release() == other.as_release()
+    }
+}
+
+impl Eq for OwnedRelease {}
+
+impl OwnedRelease {
+    /// Borrows this owned release as a regular [`Release`].
+    pub fn as_release(&self) -> Release<'_> {
+        Release {
+            raw: &self.raw,
+            package: &self.package,
+            version_raw: &self.version_raw,
+            version: self.version.as_ref().map(OwnedVersion::as_version),
+        }
+    }
+}
+
+impl From<Release<'_>> for OwnedRelease {
+    fn from(release: Release<'_>) -> OwnedRelease {
+        OwnedRelease {
+            raw: release.raw.to_string(),
+            package: release.package.to_string(),
+            version_raw: release.version_raw.to_string(),
+            version: release.version.map(OwnedVersion::from),
+        }
+    }
+}
+
+impl FromStr for OwnedRelease {
+    type Err = InvalidRelease;
+
+    fn from_str(release: &str) -> Result<OwnedRelease, InvalidRelease> {
+        Release::parse(release).map(OwnedRelease::from)
+    }
+}
+
+impl fmt::Display for OwnedRelease {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.as_release(), f)
+    }
+}
+
+impl PartialOrd for OwnedRelease {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OwnedRelease {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_release().cmp(&other.as_release())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for OwnedRelease {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for OwnedRelease {
+    fn deserialize<D>(deserializer: D) -> Result<OwnedRelease, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(DeError::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_stable() {
+        let version = Version::parse("1.0.0").unwrap();
+        assert_eq!(version.channel(), ReleaseChannel::Stable);
+    }
+
+    #[test]
+    fn test_channel_beta() {
+        let version = Version::parse("1.0.0-beta.3").unwrap();
+        assert_eq!(version.channel(), ReleaseChannel::Beta);
+    }
+
+    #[test]
+    fn test_channel_release_candidate() {
+        let version = Version::parse("2.1-rc1").unwrap();
+        assert_eq!(version.channel(), ReleaseChannel::ReleaseCandidate);
+    }
+
+    #[test]
+    fn test_channel_alpha_and_dev() {
+        assert_eq!(
+            Version::parse("1.0.0-alpha.1").unwrap().channel(),
+            ReleaseChannel::Alpha
+        );
+        assert_eq!(
+            Version::parse("1.0.0-dev.1").unwrap().channel(),
+            ReleaseChannel::Dev
+        );
+        assert_eq!(
+            Version::parse("1.0.0-nightly.1").unwrap().channel(),
+            ReleaseChannel::Dev
+        );
+    }
+
+    #[test]
+    fn test_channel_other() {
+        let version = Version::parse("1.0.0-custom.1").unwrap();
+        assert_eq!(version.channel(), ReleaseChannel::Other("custom"));
+    }
+
+    #[test]
+    fn test_version_req_whitespace_and_comma_separators_are_equivalent() {
+        let req_space = VersionReq::parse(">=1.2.0 <2.0.0").unwrap();
+        let req_comma = VersionReq::parse(">=1.2.0,<2.0.0").unwrap();
+        for v in &["1.2.0", "1.9.9", "2.0.0", "1.1.9"] {
+            let version = Version::parse(v).unwrap();
+            assert_eq!(version.matches(&req_space), version.matches(&req_comma));
+        }
+        assert!(Version::parse("1.5.0").unwrap().matches(&req_space));
+        assert!(!Version::parse("2.0.0").unwrap().matches(&req_space));
+        assert!(!Version::parse("1.1.9").unwrap().matches(&req_space));
+    }
+
+    #[test]
+    fn test_version_req_caret() {
+        let req = VersionReq::parse("^1.2.3").unwrap();
+        assert!(Version::parse("1.2.3").unwrap().matches(&req));
+        assert!(Version::parse("1.9.9").unwrap().matches(&req));
+        assert!(!Version::parse("2.0.0").unwrap().matches(&req));
+        assert!(!Version::parse("1.2.2").unwrap().matches(&req));
+
+        let req = VersionReq::parse("^0.2.3").unwrap();
+        assert!(Version::parse("0.2.3").unwrap().matches(&req));
+        assert!(!Version::parse("0.3.0").unwrap().matches(&req));
+
+        let req = VersionReq::parse("^0.0.3").unwrap();
+        assert!(Version::parse("0.0.3").unwrap().matches(&req));
+        assert!(!Version::parse("0.0.4").unwrap().matches(&req));
+    }
+
+    #[test]
+    fn test_version_req_tilde() {
+        let req = VersionReq::parse("~1.2.3").unwrap();
+        assert!(Version::parse("1.2.3").unwrap().matches(&req));
+        assert!(Version::parse("1.2.9").unwrap().matches(&req));
+        assert!(!Version::parse("1.3.0").unwrap().matches(&req));
+
+        let req = VersionReq::parse("~1.2").unwrap();
+        assert!(Version::parse("1.2.0").unwrap().matches(&req));
+        assert!(!Version::parse("1.3.0").unwrap().matches(&req));
+    }
+
+    #[test]
+    fn test_version_req_wildcard() {
+        let req = VersionReq::parse("*").unwrap();
+        assert!(Version::parse("1.2.3").unwrap().matches(&req));
+        assert!(Version::parse("0.0.1").unwrap().matches(&req));
+    }
+
+    #[test]
+    fn test_version_req_prerelease_requires_explicit_match() {
+        let req = VersionReq::parse(">=1.2.0").unwrap();
+        assert!(!Version::parse("1.2.0-alpha").unwrap().matches(&req));
+
+        let req = VersionReq::parse(">=1.2.0-alpha").unwrap();
+        assert!(Version::parse("1.2.0-alpha").unwrap().matches(&req));
+        assert!(!Version::parse("1.3.0-alpha").unwrap().matches(&req));
+    }
+
+    #[test]
+    fn test_owned_version_from_str() {
+        let owned: OwnedVersion = "1.2.3-beta.1".parse().unwrap();
+        assert_eq!(owned.as_version(), Version::parse("1.2.3-beta.1").unwrap());
+        assert_eq!(owned.to_string(), "1.2.3-beta.1");
+    }
+
+    #[test]
+    fn test_owned_release_from_str() {
+        let owned: OwnedRelease = "myapp@1.2.3".parse().unwrap();
+        assert_eq!(owned.as_release(), Release::parse("myapp@1.2.3").unwrap());
+        assert_eq!(owned.to_string(), "myapp@1.2.3");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_owned_version_serde_round_trip() {
+        let owned: OwnedVersion = "1.2.3-beta.1".parse().unwrap();
+        let json = serde_json::to_string(&owned).unwrap();
+        assert_eq!(json, "\"1.2.3-beta.1\"");
+        let roundtripped: OwnedVersion = serde_json::from_str(&json).unwrap();
+        assert_eq!(roundtripped, owned);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_owned_release_serde_round_trip() {
+        let owned: OwnedRelease = "myapp@1.2.3".parse().unwrap();
+        let json = serde_json::to_string(&owned).unwrap();
+        assert_eq!(json, "\"myapp@1.2.3\"");
+        let roundtripped: OwnedRelease = serde_json::from_str(&json).unwrap();
+        assert_eq!(roundtripped, owned);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_version_serialize_struct_field_count_matches_fields_written() {
+        // `serialize_struct`'s declared field count becomes the map header in
+        // length-prefixed formats (e.g. MessagePack); if it understates the
+        // number of `serialize_field` calls, trailing fields are silently
+        // dropped on decode instead of erroring.
+        let version = Version::parse("1.2.3-beta.1").unwrap();
+        let json = serde_json::to_value(&version).unwrap();
+        let fields = json.as_object().unwrap();
+        assert_eq!(fields.len(), 7);
+        assert!(fields.contains_key("channel"));
+    }
+
+    #[test]
+    fn test_version_equality_ignores_raw_and_components() {
+        assert_eq!(
+            Version::parse("1.0").unwrap(),
+            Version::parse("1.0.0").unwrap()
+        );
+        assert!(Version::parse("1.0.0-alpha").unwrap() < Version::parse("1.0.0").unwrap());
+    }
+
+    #[test]
+    fn test_version_ordering_precedence() {
+        assert!(Version::parse("1.0.0-alpha").unwrap() < Version::parse("1.0.0-alpha.1").unwrap());
+        assert!(
+            Version::parse("1.0.0-alpha.1").unwrap() < Version::parse("1.0.0-alpha.beta").unwrap()
+        );
+        assert!(
+            Version::parse("1.0.0-alpha.beta").unwrap() < Version::parse("1.0.0-beta").unwrap()
+        );
+        assert!(Version::parse("1.0.0-beta").unwrap() < Version::parse("1.0.0-beta.2").unwrap());
+        assert!(Version::parse("1.0.0-beta.2").unwrap() < Version::parse("1.0.0-beta.11").unwrap());
+        assert!(Version::parse("1.0.0-beta.11").unwrap() < Version::parse("1.0.0-rc.1").unwrap());
+        assert!(Version::parse("1.0.0-rc.1").unwrap() < Version::parse("1.0.0").unwrap());
+    }
+
+    #[test]
+    fn test_release_eq_and_ord_agree() {
+        let a = Release::parse("myapp@1.0").unwrap();
+        let b = Release::parse("myapp@1.0.0").unwrap();
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_release_ordering_does_not_mix_parsed_and_unparsed_versions() {
+        // `pkg@1@2` fails to parse as a `Version` and falls back to comparing
+        // `version_raw`; it must not be interleaved between numerically
+        // ordered, successfully parsed versions.
+        let low = Release::parse("pkg@2.0.0").unwrap();
+        let high = Release::parse("pkg@10.0.0").unwrap();
+        let unparsed = Release::parse("pkg@1@2").unwrap();
+        assert!(unparsed.version().is_none());
+
+        assert!(low < high);
+        assert!(unparsed < low);
+        assert!(unparsed < high);
+
+        let mut releases = vec![
+            high.clone(),
+            unparsed.clone(),
+            low.clone(),
+            low.clone(),
+            high.clone(),
+        ];
+        releases.sort();
+        assert_eq!(
+            releases,
+            vec![unparsed, low.clone(), low, high.clone(), high]
+        );
+    }
+
+    #[test]
+    fn test_pre_identifiers() {
+        let version = Version::parse("1.0.0-beta.7").unwrap();
+        let ids: Vec<_> = version.pre_identifiers().collect();
+        assert_eq!(
+            ids,
+            vec![Identifier::AlphaNumeric("beta"), Identifier::Numeric(7),]
+        );
+    }
+
+    #[test]
+    fn test_pre_identifiers_empty_when_no_prerelease() {
+        let version = Version::parse("1.0.0").unwrap();
+        assert_eq!(version.pre_identifiers().count(), 0);
+    }
+
+    #[test]
+    fn test_build_identifiers() {
+        let version = Version::parse("1.0.0+build.42").unwrap();
+        let ids: Vec<_> = version.build_identifiers().collect();
+        assert_eq!(
+            ids,
+            vec![Identifier::AlphaNumeric("build"), Identifier::Numeric(42),]
+        );
+    }
+
+    #[test]
+    fn test_build_identifiers_empty_when_no_build_code() {
+        let version = Version::parse("1.0.0-beta").unwrap();
+        assert_eq!(version.build_identifiers().count(), 0);
+    }
+}